@@ -0,0 +1,233 @@
+//! Codegen for `#[derive(Gatekeeper)]`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Member};
+
+use crate::internals::{self, FieldModel, Model, Rule, TransformRule};
+
+pub(crate) fn expand_derive_gatekeeper(input: &mut DeriveInput) -> syn::Result<TokenStream> {
+    let model = internals::parse(input)?;
+
+    let validate_impl = expand_validate(&model);
+    let transform_impl = expand_transform(&model);
+
+    Ok(quote! {
+        #validate_impl
+        #transform_impl
+    })
+}
+
+fn expand_validate(model: &Model) -> TokenStream {
+    let ident = &model.ident;
+    let (impl_generics, ty_generics, where_clause) = model.generics.split_for_impl();
+    let context = &model.context;
+
+    let field_checks: Vec<_> = model.fields.iter().map(field_rule_checks).collect();
+
+    quote! {
+        impl #impl_generics ::gatekeeper::validate::Validate for #ident #ty_generics #where_clause {
+            type Context = #context;
+
+            fn validate_into(
+                &self,
+                ctx: &Self::Context,
+                parent: &mut dyn FnMut() -> ::gatekeeper::error::Path,
+                report: &mut ::gatekeeper::error::Report,
+            ) {
+                #(#field_checks)*
+            }
+        }
+    }
+}
+
+/// Generates every check for a single field, including recursing into
+/// `#[keep(inner(...))]` items. Builds `&self.<member>` as the checked value
+/// and `parent().join(<key>)` as its path internally.
+fn field_rule_checks(field: &FieldModel) -> TokenStream {
+    let member = &field.member;
+    let key = member_key(field);
+    let value = quote!(&self.#member);
+    let path = quote!(parent().join(#key));
+
+    let mut checks = TokenStream::new();
+    for rule in &field.rules {
+        if let Rule::Inner(inner_rules) = rule {
+            let inner_checks = inner_rules.iter().map(|rule| {
+                emit_rule(
+                    rule,
+                    &quote!(item),
+                    &quote!(parent().join(#key).join(index)),
+                )
+            });
+            checks.extend(quote! {
+                for (index, item) in ::std::iter::IntoIterator::into_iter(&self.#member).enumerate() {
+                    #(#inner_checks)*
+                }
+            });
+        } else {
+            checks.extend(emit_rule(rule, &value, &path));
+        }
+    }
+    checks
+}
+
+fn emit_rule(rule: &Rule, value: &TokenStream, path: &TokenStream) -> TokenStream {
+    match rule {
+        Rule::Dive => quote! {
+            ::gatekeeper::validate::Validate::validate_into(#value, ctx, &mut || #path, report);
+        },
+        Rule::Email => quote! {
+            if !::gatekeeper::rules::email(::std::convert::AsRef::<str>::as_ref(#value)) {
+                report.append(#path, ::gatekeeper::error::Error::new("not a valid email address").with_code("email"));
+            }
+        },
+        Rule::Url => quote! {
+            if !::gatekeeper::rules::url(::std::convert::AsRef::<str>::as_ref(#value)) {
+                report.append(#path, ::gatekeeper::error::Error::new("not a valid URL").with_code("url"));
+            }
+        },
+        Rule::Length { min, max } => {
+            let min_check = min.as_ref().map(|min| {
+                quote! {
+                    if ::gatekeeper::rules::HasLength::length(#value) < (#min) {
+                        report.append(#path, ::gatekeeper::error::Error::new("length is below the minimum").with_code("length.min"));
+                    }
+                }
+            });
+            let max_check = max.as_ref().map(|max| {
+                quote! {
+                    if ::gatekeeper::rules::HasLength::length(#value) > (#max) {
+                        report.append(#path, ::gatekeeper::error::Error::new("length is above the maximum").with_code("length.max"));
+                    }
+                }
+            });
+            quote! {
+                #min_check
+                #max_check
+            }
+        }
+        Rule::Range { min, max } => {
+            let min_check = min.as_ref().map(|min| {
+                quote! {
+                    if !(#value >= &(#min)) {
+                        report.append(#path, ::gatekeeper::error::Error::new("value is below the minimum").with_code("range.min"));
+                    }
+                }
+            });
+            let max_check = max.as_ref().map(|max| {
+                quote! {
+                    if !(#value <= &(#max)) {
+                        report.append(#path, ::gatekeeper::error::Error::new("value is above the maximum").with_code("range.max"));
+                    }
+                }
+            });
+            quote! {
+                #min_check
+                #max_check
+            }
+        }
+        Rule::Pattern(lit) => quote! {
+            {
+                static RE: ::std::sync::OnceLock<::gatekeeper::rules::CompiledPattern> =
+                    ::std::sync::OnceLock::new();
+                let re = RE.get_or_init(|| ::gatekeeper::rules::compile_pattern(#lit));
+                if !::gatekeeper::rules::pattern(::std::convert::AsRef::<str>::as_ref(#value), re) {
+                    report.append(#path, ::gatekeeper::error::Error::new("does not match pattern").with_code("pattern"));
+                }
+            }
+        },
+        Rule::Contains(lit) => quote! {
+            if !::gatekeeper::rules::contains(::std::convert::AsRef::<str>::as_ref(#value), #lit) {
+                report.append(#path, ::gatekeeper::error::Error::new(::std::format!("must contain {:?}", #lit)).with_code("contains"));
+            }
+        },
+        Rule::Custom(path_fn) => quote! {
+            if let ::std::result::Result::Err(error) = #path_fn(#value, ctx) {
+                report.append(#path, error);
+            }
+        },
+        Rule::Inner(_) => {
+            // `internals::parse_rule` rejects `inner(inner(...))` before this
+            // ever runs, so a bare `Rule::Inner` only reaches `emit_rule` via
+            // `field_rule_checks`, which handles it directly.
+            unreachable!("nested `inner(...)` is rejected at parse time")
+        }
+    }
+}
+
+/// Always emits a `Transform` impl, even for structs with no transform
+/// directives or `dive` fields of their own (its body is then empty). This
+/// is load-bearing: a struct `#[keep(dive)]`d into from elsewhere must
+/// implement `Transform` unconditionally, since the derive has no way to
+/// know from inside this function whether some other struct dives into it.
+///
+/// This also can't be gated behind `#[cfg(feature = "transform")]` in the
+/// generated code: a `cfg` spliced into derive output is evaluated against
+/// the *consuming* crate's features, not `gatekeeper`'s, so it would almost
+/// never match. `gatekeeper::transform` is therefore compiled regardless of
+/// `gatekeeper`'s `transform` feature, which only decides whether
+/// `Unvalidated::validate` runs the transform pass.
+fn expand_transform(model: &Model) -> TokenStream {
+    let ident = &model.ident;
+    let (impl_generics, ty_generics, where_clause) = model.generics.split_for_impl();
+    let context = &model.context;
+
+    let field_transforms = model.fields.iter().map(|field| {
+        let member = &field.member;
+        let key = member_key(field);
+        let dive = field.rules.iter().any(|rule| matches!(rule, Rule::Dive));
+
+        let builtins = field.transforms.iter().map(|transform| match transform {
+            TransformRule::Trim => quote!(::gatekeeper::transform::rules::trim(&mut self.#member);),
+            TransformRule::Lowercase => {
+                quote!(::gatekeeper::transform::rules::lowercase(&mut self.#member);)
+            }
+            TransformRule::Uppercase => {
+                quote!(::gatekeeper::transform::rules::uppercase(&mut self.#member);)
+            }
+            TransformRule::NormalizeUnicode => {
+                quote!(::gatekeeper::transform::rules::normalize_unicode(&mut self.#member);)
+            }
+        });
+
+        let dive_call = dive.then(|| {
+            quote! {
+                ::gatekeeper::transform::Transform::transform_into(
+                    &mut self.#member,
+                    ctx,
+                    &mut || parent().join(#key),
+                );
+            }
+        });
+
+        quote! {
+            #(#builtins)*
+            #dive_call
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::gatekeeper::transform::Transform for #ident #ty_generics #where_clause {
+            type Context = #context;
+
+            fn transform_into(
+                &mut self,
+                ctx: &Self::Context,
+                parent: &mut dyn FnMut() -> ::gatekeeper::error::Path,
+            ) {
+                #(#field_transforms)*
+            }
+        }
+    }
+}
+
+fn member_key(field: &FieldModel) -> TokenStream {
+    match &field.member {
+        Member::Named(ident) => {
+            let name = ident.to_string();
+            quote!(#name)
+        }
+        Member::Unnamed(_) => quote!(::gatekeeper::error::NoKey::default()),
+    }
+}
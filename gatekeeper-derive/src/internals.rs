@@ -0,0 +1,289 @@
+//! Shared IR parsed out of `#[derive(Gatekeeper)]` input.
+//!
+//! [`crate::derive`] consumes the [`Model`] produced here to generate the
+//! `Validate`/`Transform` impls; this module only knows how to read the
+//! `#[keep(...)]` attributes into a structured form.
+
+use syn::parse::{Parse, ParseStream};
+use syn::{Data, DeriveInput, Expr, Fields, Ident, LitStr, Member, Path as SynPath, Token, Type};
+
+/// The parsed shape of a single `#[derive(Gatekeeper)]` input.
+pub(crate) struct Model {
+    pub ident: Ident,
+    pub generics: syn::Generics,
+    pub context: Type,
+    pub fields: Vec<FieldModel>,
+}
+
+pub(crate) struct FieldModel {
+    pub member: Member,
+    pub rules: Vec<Rule>,
+    pub transforms: Vec<TransformRule>,
+}
+
+/// A single validation directive parsed from `#[keep(...)]`.
+pub(crate) enum Rule {
+    /// `#[keep(dive)]` — recurse into a field that implements `Validate`
+    /// (and, when present, `Transform`) itself.
+    Dive,
+    /// `#[keep(length(min = ..., max = ...))]`
+    Length {
+        min: Option<Expr>,
+        max: Option<Expr>,
+    },
+    /// `#[keep(range(min = ..., max = ...))]`
+    Range {
+        min: Option<Expr>,
+        max: Option<Expr>,
+    },
+    /// `#[keep(pattern("regex"))]`
+    Pattern(LitStr),
+    /// `#[keep(email)]`
+    Email,
+    /// `#[keep(url)]`
+    Url,
+    /// `#[keep(contains("needle"))]`
+    Contains(LitStr),
+    /// `#[keep(custom(path::to::fn))]`
+    Custom(SynPath),
+    /// `#[keep(inner(...))]` — applies the given rules to every item of a
+    /// collection field instead of to the field itself.
+    Inner(Vec<Rule>),
+}
+
+/// A single transform directive parsed from `#[keep(transform(...))]`.
+pub(crate) enum TransformRule {
+    Trim,
+    Lowercase,
+    Uppercase,
+    NormalizeUnicode,
+}
+
+pub(crate) fn parse(input: &DeriveInput) -> syn::Result<Model> {
+    let context = parse_container_context(input)?;
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            syn::spanned::Spanned::span(input),
+            "`Gatekeeper` can currently only be derived for structs",
+        ));
+    };
+
+    let fields = match &data.fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .enumerate()
+            .map(|(index, field)| parse_field(index, field))
+            .collect::<syn::Result<Vec<_>>>()?,
+        Fields::Unnamed(fields) => fields
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(index, field)| parse_field(index, field))
+            .collect::<syn::Result<Vec<_>>>()?,
+        Fields::Unit => Vec::new(),
+    };
+
+    Ok(Model {
+        ident: input.ident.clone(),
+        generics: input.generics.clone(),
+        context,
+        fields,
+    })
+}
+
+fn parse_container_context(input: &DeriveInput) -> syn::Result<Type> {
+    for attr in &input.attrs {
+        if !attr.path().is_ident("keep") {
+            continue;
+        }
+
+        let mut context = None;
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("context") {
+                let ty: Type = meta.value()?.parse()?;
+                context = Some(ty);
+                Ok(())
+            } else {
+                Ok(())
+            }
+        })?;
+
+        if let Some(context) = context {
+            return Ok(context);
+        }
+    }
+
+    // No `#[keep(context = ...)]` on the container: default to `()`, which
+    // every `Validate::Context: Default` bound is happy with.
+    Ok(syn::parse_quote!(()))
+}
+
+fn parse_field(index: usize, field: &syn::Field) -> syn::Result<FieldModel> {
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(index.into()),
+    };
+
+    let mut rules = Vec::new();
+    let mut transforms = Vec::new();
+
+    for attr in &field.attrs {
+        if !attr.path().is_ident("keep") {
+            continue;
+        }
+
+        let FieldDirectives {
+            rules: mut parsed_rules,
+            transforms: mut parsed_transforms,
+        } = attr.parse_args()?;
+        rules.append(&mut parsed_rules);
+        transforms.append(&mut parsed_transforms);
+    }
+
+    Ok(FieldModel {
+        member,
+        rules,
+        transforms,
+    })
+}
+
+/// The parsed contents of a single field-level `#[keep(...)]` attribute.
+struct FieldDirectives {
+    rules: Vec<Rule>,
+    transforms: Vec<TransformRule>,
+}
+
+impl Parse for FieldDirectives {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut rules = Vec::new();
+        let mut transforms = Vec::new();
+
+        while !input.is_empty() {
+            let ident: Ident = input.parse()?;
+            if ident == "transform" {
+                let content;
+                syn::parenthesized!(content in input);
+                transforms.extend(parse_transform_list(&content)?);
+            } else {
+                rules.push(parse_rule(ident, input)?);
+            }
+
+            if input.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(Self { rules, transforms })
+    }
+}
+
+fn parse_rule(ident: Ident, input: ParseStream) -> syn::Result<Rule> {
+    match ident.to_string().as_str() {
+        "dive" => Ok(Rule::Dive),
+        "email" => Ok(Rule::Email),
+        "url" => Ok(Rule::Url),
+        "length" => {
+            let content;
+            syn::parenthesized!(content in input);
+            let (min, max) = parse_min_max(&content)?;
+            Ok(Rule::Length { min, max })
+        }
+        "range" => {
+            let content;
+            syn::parenthesized!(content in input);
+            let (min, max) = parse_min_max(&content)?;
+            Ok(Rule::Range { min, max })
+        }
+        "pattern" => {
+            let content;
+            syn::parenthesized!(content in input);
+            Ok(Rule::Pattern(content.parse()?))
+        }
+        "contains" => {
+            let content;
+            syn::parenthesized!(content in input);
+            Ok(Rule::Contains(content.parse()?))
+        }
+        "custom" => {
+            let content;
+            syn::parenthesized!(content in input);
+            Ok(Rule::Custom(content.parse()?))
+        }
+        "inner" => {
+            let content;
+            syn::parenthesized!(content in input);
+            let mut inner = Vec::new();
+            while !content.is_empty() {
+                let ident: Ident = content.parse()?;
+                if ident == "inner" {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "`inner` cannot be nested inside another `inner`",
+                    ));
+                }
+                inner.push(parse_rule(ident, &content)?);
+                if content.peek(Token![,]) {
+                    content.parse::<Token![,]>()?;
+                }
+            }
+            Ok(Rule::Inner(inner))
+        }
+        other => Err(syn::Error::new(
+            ident.span(),
+            format!("unrecognized `keep` rule `{other}`"),
+        )),
+    }
+}
+
+fn parse_min_max(input: ParseStream) -> syn::Result<(Option<Expr>, Option<Expr>)> {
+    let mut min = None;
+    let mut max = None;
+
+    while !input.is_empty() {
+        let ident: Ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let expr: Expr = input.parse()?;
+        match ident.to_string().as_str() {
+            "min" => min = Some(expr),
+            "max" => max = Some(expr),
+            other => {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!("unrecognized argument `{other}`, expected `min` or `max`"),
+                ));
+            }
+        }
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+    }
+
+    Ok((min, max))
+}
+
+fn parse_transform_list(input: ParseStream) -> syn::Result<Vec<TransformRule>> {
+    let mut transforms = Vec::new();
+
+    while !input.is_empty() {
+        let ident: Ident = input.parse()?;
+        transforms.push(match ident.to_string().as_str() {
+            "trim" => TransformRule::Trim,
+            "lowercase" => TransformRule::Lowercase,
+            "uppercase" => TransformRule::Uppercase,
+            "normalize_unicode" => TransformRule::NormalizeUnicode,
+            other => {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!("unrecognized `transform` directive `{other}`"),
+                ));
+            }
+        });
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+    }
+
+    Ok(transforms)
+}
@@ -0,0 +1,71 @@
+//! A structured emitter for rendering a [`Report`] in multiple formats, in
+//! the spirit of rustc's `Emitter::emit_*` methods.
+//!
+//! This lets web services and CLIs return validation failures as JSON
+//! without hand-rolling serialization over [`Report::iter`].
+
+use crate::Report;
+
+/// The format a [`Report`] can be rendered as via [`Report::emit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// The existing human-readable [`core::fmt::Display`] output.
+    Human,
+    /// Compact JSON: one object per error, as described on [`Report`]'s
+    /// `Serialize` impl.
+    Json,
+    /// Pretty-printed JSON.
+    JsonPretty,
+}
+
+impl Report {
+    /// Renders this report in the given [`OutputFormat`].
+    ///
+    /// JSON serialization of a [`Report`] is infallible, so this returns a
+    /// `String` directly rather than threading a `Result` through every
+    /// caller.
+    pub fn emit(&self, format: OutputFormat) -> String {
+        match format {
+            OutputFormat::Human => self.to_string(),
+            OutputFormat::Json => {
+                serde_json::to_string(self).expect("Report serialization is infallible")
+            }
+            OutputFormat::JsonPretty => {
+                serde_json::to_string_pretty(self).expect("Report serialization is infallible")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{Error, Path};
+
+    use super::*;
+
+    fn sample_report() -> Report {
+        let mut report = Report::new();
+        report.append(Path::new("username"), Error::new("must not be empty"));
+        report
+    }
+
+    #[test]
+    fn human_matches_display() {
+        let report = sample_report();
+        assert_eq!(report.emit(OutputFormat::Human), report.to_string());
+    }
+
+    #[test]
+    fn json_round_trips_message_and_path() {
+        let report = sample_report();
+        let json = report.emit(OutputFormat::Json);
+        assert!(json.contains("\"message\":\"must not be empty\""));
+        assert!(json.contains("\"path\":\"username\""));
+    }
+
+    #[test]
+    fn json_pretty_is_multiline() {
+        let report = sample_report();
+        assert!(report.emit(OutputFormat::JsonPretty).contains('\n'));
+    }
+}
@@ -0,0 +1,88 @@
+//! An arena that deduplicates repeated [`Path`](super::Path) components.
+//!
+//! Deeply nested or collection-heavy validation produces many duplicate
+//! keys (field names repeated across thousands of items). Borrowing the
+//! arena/interning approach rustc uses for repeated symbols, [`PathInterner`]
+//! lets a [`Path`](super::Path) carry a `u32` id per component instead of
+//! cloning a `CompactString`.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use compact_str::CompactString;
+
+/// Deduplicates path components (field names, map keys) behind `u32` ids.
+///
+/// Every id handed out by a given `PathInterner` is only valid for that same
+/// interner; a [`Path`](super::Path) built with [`Path::interned`](super::Path::interned)
+/// carries an `Arc` to the interner it was built from so it can always
+/// resolve its own ids.
+#[derive(Debug, Default)]
+pub struct PathInterner {
+    strings: RwLock<Vec<CompactString>>,
+    ids: RwLock<HashMap<CompactString, u32>>,
+}
+
+impl PathInterner {
+    /// Creates an empty interner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Interns `value`, returning its id. Interning an equal string again
+    /// returns the same id.
+    pub fn intern(&self, value: impl Into<CompactString>) -> u32 {
+        let value = value.into();
+
+        if let Some(&id) = self.ids.read().unwrap().get(&value) {
+            return id;
+        }
+
+        let mut strings = self.strings.write().unwrap();
+        let mut ids = self.ids.write().unwrap();
+
+        // Another thread may have interned `value` while we were waiting for
+        // the write locks; check again before allocating a new id.
+        if let Some(&id) = ids.get(&value) {
+            return id;
+        }
+
+        let id = strings.len() as u32;
+        strings.push(value.clone());
+        ids.insert(value, id);
+        id
+    }
+
+    /// Resolves `id` back to the string it was interned from.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` was not produced by this interner.
+    pub fn resolve(&self, id: u32) -> CompactString {
+        self.strings.read().unwrap()[id as usize].clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interning_the_same_string_twice_returns_the_same_id() {
+        let interner = PathInterner::new();
+        let first = interner.intern("username");
+        let second = interner.intern("username");
+        assert_eq!(first, second);
+        assert_eq!(interner.resolve(first), "username");
+    }
+
+    #[test]
+    fn distinct_strings_get_distinct_resolvable_ids() {
+        let interner = PathInterner::new();
+        let username_id = interner.intern("username");
+        let age_id = interner.intern("age");
+        assert_ne!(username_id, age_id);
+        assert_eq!(interner.resolve(username_id), "username");
+        assert_eq!(interner.resolve(age_id), "age");
+    }
+}
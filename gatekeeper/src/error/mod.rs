@@ -2,12 +2,20 @@
 //!
 //! The entrypoint of this module is the [`Error`] type.
 
+#[cfg(feature = "intern")]
+mod intern;
 mod rc_list;
 
+#[cfg(feature = "intern")]
+use std::sync::Arc;
+
 use compact_str::{CompactString, ToCompactString};
 use rc_list::List;
 use smallvec::SmallVec;
 
+#[cfg(feature = "intern")]
+pub use intern::PathInterner;
+
 const DEFAULT_MAX_ERROR_REPORT: usize = 10;
 
 /// A validation error report.
@@ -18,6 +26,12 @@ const DEFAULT_MAX_ERROR_REPORT: usize = 10;
 #[derive(Clone, Debug)]
 pub struct Report {
     errors: SmallVec<[(Path, Error); DEFAULT_MAX_ERROR_REPORT]>,
+    /// The interner every [`Path`] in `errors` was built against, if this
+    /// report was created with [`Report::with_interner`]. Kept alongside the
+    /// errors so the report remains self-sufficient: every id in it stays
+    /// resolvable for as long as the report lives.
+    #[cfg(feature = "intern")]
+    interner: Option<Arc<PathInterner>>,
 }
 
 impl Report {
@@ -25,8 +39,27 @@ impl Report {
     pub fn new() -> Self {
         Self {
             errors: SmallVec::new(),
+            #[cfg(feature = "intern")]
+            interner: None,
+        }
+    }
+
+    /// Create an empty [`Report`] that will hold [`Path`]s built against
+    /// `interner`. See [`Validate::validate_with_interner`](crate::validate::Validate::validate_with_interner).
+    #[cfg(feature = "intern")]
+    pub fn with_interner(interner: Arc<PathInterner>) -> Self {
+        Self {
+            errors: SmallVec::new(),
+            interner: Some(interner),
         }
     }
+
+    /// The interner this report's [`Path`]s were built against, if any.
+    #[cfg(feature = "intern")]
+    pub fn interner(&self) -> Option<&Arc<PathInterner>> {
+        self.interner.as_ref()
+    }
+
     /// Append an [`Error`] into this report at the given [`Path`].
     pub fn append(&mut self, path: Path, error: Error) {
         self.errors.push((path, error));
@@ -42,10 +75,78 @@ impl Report {
         self.errors.is_empty()
     }
 
+    /// Returns `true` if the report contains at least one entry at
+    /// [`Severity::Error`], ignoring any [`Severity::Warning`] or
+    /// [`Severity::Info`] entries.
+    pub fn has_errors(&self) -> bool {
+        self.errors
+            .iter()
+            .any(|(_, error)| error.severity() == Severity::Error)
+    }
+
+    /// Returns an iterator over the entries at or above `min` severity.
+    pub fn filter_severity(&self, min: Severity) -> impl Iterator<Item = &(Path, Error)> {
+        self.errors
+            .iter()
+            .filter(move |(_, error)| error.severity() >= min)
+    }
+
     /// Converts into the inner validation errors.
     pub fn into_inner(self) -> SmallVec<[(Path, Error); DEFAULT_MAX_ERROR_REPORT]> {
         self.errors
     }
+
+    /// Returns an iterator over the errors located at exactly `path`.
+    pub fn at<'a>(&'a self, path: &'a Path) -> impl Iterator<Item = &'a Error> {
+        self.errors
+            .iter()
+            .filter(move |(p, _)| p == path)
+            .map(|(_, error)| error)
+    }
+
+    /// Returns an iterator over the errors whose path starts with `prefix`'s
+    /// component sequence, e.g. the prefix `items[3]` matches the path
+    /// `items[3].name` but not `items[30]`.
+    pub fn under<'a>(&'a self, prefix: &'a Path) -> impl Iterator<Item = &'a Error> {
+        self.errors
+            .iter()
+            .filter(move |(p, _)| path_starts_with(p, prefix))
+            .map(|(_, error)| error)
+    }
+
+    /// Groups errors by their [`Path`], one group per distinct path, in the
+    /// order each path first appears in this report.
+    pub fn group_by_path(&self) -> impl Iterator<Item = (Path, impl Iterator<Item = &Error>)> {
+        let mut seen: Vec<Path> = Vec::new();
+        for (path, _) in self.iter() {
+            if !seen.iter().any(|seen_path| seen_path == path) {
+                seen.push(path.clone());
+            }
+        }
+
+        seen.into_iter().map(move |path| {
+            let matches = self.errors.iter().filter({
+                let path = path.clone();
+                move |(p, _)| *p == path
+            });
+            (path, matches.map(|(_, error)| error))
+        })
+    }
+}
+
+/// Whether `path`'s component sequence (root-first) starts with `prefix`'s.
+fn path_starts_with(path: &Path, prefix: &Path) -> bool {
+    let path_components: TempComponents = path.__iter().rev().collect();
+    let prefix_components: TempComponents = prefix.__iter().rev().collect();
+
+    if prefix_components.len() > path_components.len() {
+        return false;
+    }
+
+    path_components
+        .iter()
+        .zip(prefix_components.iter())
+        .all(|(a, b)| a == b)
 }
 
 impl core::fmt::Display for Report {
@@ -63,21 +164,98 @@ impl core::fmt::Display for Report {
 
 impl core::error::Error for Report {}
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Report {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeSeq;
+
+        struct Entry<'a> {
+            path: &'a Path,
+            error: &'a Error,
+        }
+
+        impl serde::Serialize for Entry<'_> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                use serde::ser::SerializeStruct;
+
+                let mut state = serializer.serialize_struct("Entry", 5)?;
+                state.serialize_field("path", &self.path.to_string())?;
+                state.serialize_field("components", &PathComponents(self.path))?;
+                state.serialize_field("message", self.error.message())?;
+                state.serialize_field("severity", &self.error.severity())?;
+                state.serialize_field("code", &self.error.code())?;
+                state.end()
+            }
+        }
+
+        let mut seq = serializer.serialize_seq(Some(self.errors.len()))?;
+        for (path, error) in self.iter() {
+            seq.serialize_element(&Entry { path, error })?;
+        }
+        seq.end()
+    }
+}
+
+/// The severity of an [`Error`], mirroring how rustc separates diagnostic
+/// levels from the human text.
+///
+/// Variants are ordered `Info < Warning < Error`, so [`Report::filter_severity`]
+/// can use `>=` to mean "at least this severe".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Error {
     message: CompactString,
+    code: Option<CompactString>,
+    severity: Severity,
 }
 
 impl Error {
     pub fn new(message: impl ToCompactString) -> Self {
         Self {
             message: message.to_compact_string(),
+            code: None,
+            severity: Severity::Error,
         }
     }
 
+    /// Attaches a stable, machine-readable code (e.g. `"length.min"`) to this error.
+    pub fn with_code(mut self, code: impl ToCompactString) -> Self {
+        self.code = Some(code.to_compact_string());
+        self
+    }
+
+    /// Sets this error's severity. Defaults to [`Severity::Error`].
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
     pub fn message(&self) -> &str {
         self.message.as_ref()
     }
+
+    /// This error's stable code, if one was attached via [`Error::with_code`].
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
 }
 
 impl core::fmt::Display for Error {
@@ -86,9 +264,72 @@ impl core::fmt::Display for Error {
     }
 }
 
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg(feature = "serde")]
+impl serde::Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Error", 3)?;
+        state.serialize_field("message", self.message())?;
+        state.serialize_field("severity", &self.severity())?;
+        state.serialize_field("code", &self.code())?;
+        state.end()
+    }
+}
+
+/// A validation error's location within the value being validated.
+///
+/// By default a `Path` owns a `CompactString` per component. When the
+/// `intern` feature is enabled, [`Path::interned`] builds one that instead
+/// stores a `u32` id per component, resolved against a shared
+/// [`PathInterner`] — so a component repeated across many paths is stored
+/// once in the interner rather than once per path. `join` still converts
+/// each component to a `CompactString` to look it up and allocates one list
+/// node; reading the path resolves ids (a `CompactString` clone each).
+#[derive(Clone)]
 pub struct Path {
-    components: List<(Kind, CompactString)>,
+    repr: PathRepr,
+}
+
+#[derive(Clone)]
+enum PathRepr {
+    Owned(List<(Kind, CompactString)>),
+    #[cfg(feature = "intern")]
+    Interned {
+        components: List<(Kind, u32)>,
+        interner: Arc<PathInterner>,
+    },
+}
+
+impl PartialEq for Path {
+    fn eq(&self, other: &Self) -> bool {
+        self.__iter().eq(other.__iter())
+    }
+}
+
+impl Eq for Path {}
+
+impl PartialOrd for Path {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Path {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.__iter().cmp(other.__iter())
+    }
+}
+
+impl core::hash::Hash for Path {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        for component in self.__iter() {
+            component.hash(state);
+        }
+    }
 }
 
 #[doc(hidden)]
@@ -139,45 +380,132 @@ impl<T: PathComponentKind> PathComponentKind for &T {
 impl Path {
     pub fn empty() -> Self {
         Self {
-            components: List::new(),
+            repr: PathRepr::Owned(List::new()),
+        }
+    }
+
+    /// Creates an empty `Path` that interns every component it's joined
+    /// with against `interner`, storing a `u32` id per component rather than
+    /// a `CompactString`.
+    #[cfg(feature = "intern")]
+    pub fn interned(interner: Arc<PathInterner>) -> Self {
+        Self {
+            repr: PathRepr::Interned {
+                components: List::new(),
+                interner,
+            },
         }
     }
 
     pub fn len(&self) -> usize {
-        self.components.len()
+        match &self.repr {
+            PathRepr::Owned(components) => components.len(),
+            #[cfg(feature = "intern")]
+            PathRepr::Interned { components, .. } => components.len(),
+        }
     }
 
     pub fn is_empty(&self) -> bool {
-        self.components.is_empty()
+        self.len() == 0
     }
 
     pub fn new<C: PathComponentKind>(component: C) -> Self {
-        Self {
-            components: List::new().append((C::component_kind(), component.to_compact_string())),
-        }
+        Self::empty().join(component)
     }
 
     pub fn join<C: PathComponentKind>(&self, component: C) -> Self {
-        Self {
-            components: self
-                .components
-                .append((C::component_kind(), component.to_compact_string())),
+        match &self.repr {
+            PathRepr::Owned(components) => Self {
+                repr: PathRepr::Owned(
+                    components.append((C::component_kind(), component.to_compact_string())),
+                ),
+            },
+            #[cfg(feature = "intern")]
+            PathRepr::Interned {
+                components,
+                interner,
+            } => {
+                let id = interner.intern(component.to_compact_string());
+                Self {
+                    repr: PathRepr::Interned {
+                        components: components.append((C::component_kind(), id)),
+                        interner: Arc::clone(interner),
+                    },
+                }
+            }
         }
     }
 
     #[doc(hidden)]
     pub fn __iter(
         &self,
-    ) -> impl DoubleEndedIterator<Item = (Kind, &CompactString)> + ExactSizeIterator {
-        let mut components = TempComponents::with_capacity(self.components.len());
-        for (kind, component) in self.components.iter() {
-            components.push((*kind, component));
+    ) -> impl DoubleEndedIterator<Item = (Kind, CompactString)> + ExactSizeIterator {
+        match &self.repr {
+            PathRepr::Owned(components) => {
+                let mut resolved = TempComponents::with_capacity(components.len());
+                for (kind, component) in components.iter() {
+                    resolved.push((*kind, component.clone()));
+                }
+                resolved.into_iter()
+            }
+            #[cfg(feature = "intern")]
+            PathRepr::Interned {
+                components,
+                interner,
+            } => {
+                let mut resolved = TempComponents::with_capacity(components.len());
+                for (kind, id) in components.iter() {
+                    resolved.push((*kind, interner.resolve(*id)));
+                }
+                resolved.into_iter()
+            }
+        }
+    }
+}
+
+type TempComponents = SmallVec<[(Kind, CompactString); 8]>;
+
+/// Serializes a [`Path`] as a flat array of its components, root-first.
+///
+/// Index components are emitted as JSON numbers and key components as JSON
+/// strings, so `items[3].name` round-trips as `["items", 3, "name"]` without
+/// needing a tagged representation to tell the two apart.
+#[cfg(feature = "serde")]
+struct PathComponents<'a>(&'a Path);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PathComponents<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeSeq;
+
+        let components: TempComponents = self.0.__iter().rev().collect();
+        let mut seq = serializer.serialize_seq(Some(components.len()))?;
+        for (kind, component) in components {
+            match kind {
+                Kind::Index => {
+                    let index: usize = component.parse().unwrap_or_default();
+                    seq.serialize_element(&index)?;
+                }
+                Kind::Key => seq.serialize_element(component.as_str())?,
+                Kind::None => {}
+            }
         }
-        components.into_iter()
+        seq.end()
     }
 }
 
-type TempComponents<'a> = SmallVec<[(Kind, &'a CompactString); 8]>;
+#[cfg(feature = "serde")]
+impl serde::Serialize for Path {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        PathComponents(self).serialize(serializer)
+    }
+}
 
 impl core::fmt::Debug for Path {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
@@ -224,3 +552,41 @@ impl core::fmt::Display for Path {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn has_errors_ignores_warnings_and_info() {
+        let mut report = Report::new();
+        report.append(
+            Path::new("field"),
+            Error::new("heads up").with_severity(Severity::Warning),
+        );
+        report.append(
+            Path::new("field"),
+            Error::new("fyi").with_severity(Severity::Info),
+        );
+        assert!(!report.has_errors());
+
+        report.append(Path::new("field"), Error::new("actually broken"));
+        assert!(report.has_errors());
+    }
+
+    #[test]
+    fn under_matches_by_component_not_by_rendered_prefix() {
+        let mut report = Report::new();
+        let path_3 = Path::new("items").join(3usize).join("name");
+        let path_30 = Path::new("items").join(30usize).join("name");
+        report.append(path_3.clone(), Error::new("item 3 is broken"));
+        report.append(path_30, Error::new("item 30 is broken"));
+
+        let prefix = Path::new("items").join(3usize);
+        let under: Vec<&Error> = report.under(&prefix).collect();
+
+        assert_eq!(under.len(), 1);
+        assert_eq!(under[0].message(), "item 3 is broken");
+        assert_eq!(report.at(&path_3).count(), 1);
+    }
+}
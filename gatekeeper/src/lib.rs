@@ -38,28 +38,32 @@ compile_error!(
     "
 );
 
-// `transform` feature: transformation pipeline is not implemented yet.
-#[cfg(feature = "transform")]
+// `intern` feature: the interning arena needs `std::sync::RwLock`, which has
+// no `alloc`-only equivalent (unlike the `Arc` used elsewhere in this crate).
+#[cfg(all(feature = "intern", not(feature = "std")))]
 compile_error!(
-    "Gatekeeper: unsupported feature `transform` enabled\n\n\
-    The `transform' feature would add in-place or ownership-based transformations\n\
-    (e.g. trim, lowercase, normalize) integrated with derive macros.\n\n\
-    Reason: Transform semantics interact closely with ownership and smart-pointer\n\
-    behavior (e.g. Arc/Rc). We intentionally omitted this from the first release\n\
-    to avoid unsafe or surprising behavior.\n\n\
-    Consequences:\n\
-    - No built-in transform functions are available with this feature enabled.\n\
-    - The derive macro will not emit transform code; compilation will fail.\n\n\
+    "Gatekeeper: unsupported feature combination `intern` without `std`\n\n\
+    The `intern` feature's `PathInterner` is built on `std::sync::RwLock`, which\n\
+    has no `no_std` + `alloc` equivalent.\n\n\
     What to do:\n\
-    - Disable `transform` for now and apply transformations explicitly in your codebase.\n\
-    - Check the roadmap for planned transform API and examples.\n
+    - Enable `std` alongside `intern`, or\n\
+    - Disable `intern` in a `no_std` build and build `Path`s the default (owned) way.\n\
     "
 );
 
 #[cfg(all(not(feature = "std"), feature = "alloc"))]
 extern crate alloc;
 
+#[cfg(feature = "serde")]
+pub mod emit;
 pub mod error;
+pub mod rules;
+// Always compiled: every `#[derive(Gatekeeper)]` emits a `Transform` impl.
+// The `transform` feature only decides whether `Unvalidated` runs the pass.
+pub mod transform;
 pub mod validate;
 
+#[cfg(feature = "serde")]
+pub use emit::OutputFormat;
 pub use error::Report;
+pub use gatekeeper_derive::Gatekeeper;
@@ -0,0 +1,139 @@
+//! Built-in validation rules invoked by derive-generated code.
+//!
+//! These mirror [`crate::transform::rules`]: plain functions (plus the small
+//! [`HasLength`] trait below) that the derive calls inline for a field
+//! annotated with e.g. `#[keep(length(min = 1))]`, rather than trait methods
+//! on [`crate::validate::Validate`] itself, since they never need to recurse.
+//!
+//! [`pattern`] and [`url`] pull in the `regex` and `url` crates respectively,
+//! so each is gated behind its own like-named feature — the same way
+//! [`crate::transform::rules::normalize_unicode`] is gated behind `unicode`.
+
+/// Types whose length can be checked by the `length` rule.
+pub trait HasLength {
+    fn length(&self) -> usize;
+}
+
+macro_rules! impl_has_length_via_len {
+    ($($T:ty),* $(,)?) => {
+        $(
+            impl HasLength for $T {
+                fn length(&self) -> usize {
+                    self.len()
+                }
+            }
+        )*
+    };
+}
+
+impl_has_length_via_len!(str, String);
+
+impl<T> HasLength for [T] {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> HasLength for Vec<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+/// `#[keep(length(min = ..., max = ...))]`
+pub fn length<T: HasLength + ?Sized>(value: &T, min: Option<usize>, max: Option<usize>) -> bool {
+    let len = value.length();
+    min.map_or(true, |min| len >= min) && max.map_or(true, |max| len <= max)
+}
+
+/// `#[keep(range(min = ..., max = ...))]`
+pub fn range<T: PartialOrd>(value: &T, min: Option<&T>, max: Option<&T>) -> bool {
+    min.map_or(true, |min| value >= min) && max.map_or(true, |max| value <= max)
+}
+
+/// `#[keep(pattern("..."))]`
+#[cfg(feature = "regex")]
+pub fn pattern(value: &str, pattern: &CompiledPattern) -> bool {
+    pattern.is_match(value)
+}
+
+/// The compiled form of a `#[keep(pattern("..."))]` regex.
+///
+/// Derive-generated code caches one of these per call site (behind a
+/// `OnceLock`) rather than recompiling the pattern on every validation. It's
+/// named as a type alias, rather than spelling out `regex::Regex` at the
+/// call site, so that caller crates never need `regex` as a direct
+/// dependency of their own — only `gatekeeper`'s `regex` feature matters.
+#[cfg(feature = "regex")]
+pub type CompiledPattern = regex::Regex;
+
+/// Compiles `source` into a [`CompiledPattern`], for derive-generated code to
+/// cache. See [`CompiledPattern`] for why this indirection exists.
+///
+/// # Panics
+///
+/// Panics if `source` is not a valid regex.
+#[cfg(feature = "regex")]
+pub fn compile_pattern(source: &str) -> CompiledPattern {
+    CompiledPattern::new(source).expect("invalid regex in `#[keep(pattern(...))]`")
+}
+
+/// `#[keep(email)]`
+///
+/// A deliberately pragmatic check (one `@`, non-empty local part, dotted
+/// domain) rather than a full RFC 5322 parser, which accepts far more than
+/// anyone actually wants in a form field.
+pub fn email(value: &str) -> bool {
+    match value.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+        }
+        None => false,
+    }
+}
+
+/// `#[keep(url)]`
+#[cfg(feature = "url")]
+pub fn url(value: &str) -> bool {
+    url::Url::parse(value).is_ok()
+}
+
+/// `#[keep(contains("..."))]`
+pub fn contains(value: &str, needle: &str) -> bool {
+    value.contains(needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn length_checks_min_and_max_independently() {
+        assert!(length("abc", Some(1), Some(5)));
+        assert!(!length("a", Some(2), None));
+        assert!(!length("abcdef", None, Some(5)));
+    }
+
+    #[test]
+    fn range_checks_min_and_max_independently() {
+        assert!(range(&5, Some(&1), Some(&10)));
+        assert!(!range(&0, Some(&1), None));
+        assert!(!range(&11, None, Some(&10)));
+    }
+
+    #[test]
+    fn email_rejects_missing_at_or_dotless_domain() {
+        assert!(email("user@example.com"));
+        assert!(!email("user@localhost"));
+        assert!(!email("not-an-email"));
+    }
+
+    #[test]
+    fn contains_matches_substrings() {
+        assert!(contains("hello world", "world"));
+        assert!(!contains("hello world", "mars"));
+    }
+}
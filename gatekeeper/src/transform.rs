@@ -0,0 +1,70 @@
+//! The transformation pipeline, run in-place before validation.
+//!
+//! This mirrors [`crate::validate::Validate`]: derived types implement
+//! [`Transform`] by recursing into their fields field-by-field, applying any
+//! built-in transforms (see [`rules`]) before a field's own validators run.
+//! This is what lets e.g. a `trim` transform make a subsequent
+//! `length(min = 1)` rule see the trimmed value.
+
+use crate::error::Path;
+
+/// Mutates `Self` in place ahead of validation.
+///
+/// This trait should not be implemented manually; it is emitted by
+/// `#[derive(Gatekeeper)]` alongside [`crate::validate::Validate`].
+pub trait Transform {
+    /// A user-provided context, threaded through nested transforms the same
+    /// way [`crate::validate::Validate::Context`] is threaded through
+    /// validation.
+    type Context;
+
+    /// Transforms `self` in place.
+    fn transform_into(&mut self, ctx: &Self::Context, parent: &mut dyn FnMut() -> Path);
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: ?Sized + Transform> Transform for &mut T {
+    type Context = T::Context;
+
+    fn transform_into(&mut self, ctx: &Self::Context, parent: &mut dyn FnMut() -> Path) {
+        <T as Transform>::transform_into(self, ctx, parent)
+    }
+}
+
+/// Built-in, field-level transforms invoked by derive-generated code.
+///
+/// These act directly on the field's value and don't need a [`Path`] of
+/// their own, since they never fail and never recurse: unlike [`Transform`],
+/// which derived types implement to walk into nested fields, these are plain
+/// functions the derive calls inline for a field annotated with e.g.
+/// `#[keep(transform(trim))]`.
+pub mod rules {
+    /// Removes leading and trailing whitespace in place.
+    pub fn trim(value: &mut String) {
+        let trimmed = value.trim();
+        if trimmed.len() != value.len() {
+            *value = trimmed.to_owned();
+        }
+    }
+
+    /// Lowercases the value in place (Unicode-aware).
+    pub fn lowercase(value: &mut String) {
+        *value = value.to_lowercase();
+    }
+
+    /// Uppercases the value in place (Unicode-aware).
+    pub fn uppercase(value: &mut String) {
+        *value = value.to_uppercase();
+    }
+
+    /// Normalizes the value to Unicode Normalization Form C (NFC) in place.
+    #[cfg(feature = "unicode")]
+    pub fn normalize_unicode(value: &mut String) {
+        use unicode_normalization::UnicodeNormalization;
+
+        let normalized: String = value.nfc().collect();
+        if normalized != *value {
+            *value = normalized;
+        }
+    }
+}
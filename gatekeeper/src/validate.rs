@@ -2,8 +2,8 @@
 
 use core::fmt::Debug;
 
-use crate::Report;
 use crate::error::Path;
+use crate::Report;
 
 /// The core trait of this crate.
 ///
@@ -15,12 +15,19 @@ pub trait Validate {
     /// Custom validators receive a reference to this context.
     type Context;
 
-    /// Validates `Self`, returning an `Err` with an aggregate of all errors if
-    /// the validation failed.
+    /// Validates `Self`, returning the aggregate [`Report`] on success and an
+    /// `Err` of the same [`Report`] if at least one [`Severity::Error`][sev]
+    /// entry was collected.
+    ///
+    /// The `Ok` report still carries any `Warning`/`Info` entries, so callers
+    /// that care about those don't have to treat "passed validation" as
+    /// "nothing to see here" — see [`Report::filter_severity`].
     ///
     /// This method should not be implemented manually. Implement [`Validate::validate_into`] instead,
     /// because [`Validate::validate`] has a default implementation that calls [`Validate::validate_into`].
-    fn validate(&self) -> Result<(), Report>
+    ///
+    /// [sev]: crate::error::Severity::Error
+    fn validate(&self) -> Result<Report, Report>
     where
         Self::Context: Default,
     {
@@ -28,17 +35,43 @@ pub trait Validate {
         self.validate_with(&ctx)
     }
 
-    /// Validates `Self`, returning an `Err` with an aggregate of all errors if
-    /// the validation failed.
+    /// Validates `Self`, returning the aggregate [`Report`] on success and an
+    /// `Err` of the same [`Report`] if at least one [`Severity::Error`][sev]
+    /// entry was collected. See [`Validate::validate`] for why warnings are
+    /// still reachable on the `Ok` path.
     ///
     /// This method should not be implemented manually. Implement [`Validate::validate_into`] instead,
     /// because [`Validate::validate_with`] has a default implementation that calls [`Validate::validate_into`].
-    fn validate_with(&self, ctx: &Self::Context) -> Result<(), Report> {
+    ///
+    /// [sev]: crate::error::Severity::Error
+    fn validate_with(&self, ctx: &Self::Context) -> Result<Report, Report> {
         let mut report = Report::new();
         self.validate_into(ctx, &mut Path::empty, &mut report);
-        match report.is_empty() {
-            true => Ok(()),
-            false => Err(report),
+        match report.has_errors() {
+            false => Ok(report),
+            true => Err(report),
+        }
+    }
+
+    /// Validates `Self` like [`Validate::validate_with`], but builds the
+    /// root [`Path`] from `interner` so every path component is deduplicated
+    /// behind a `u32` id instead of cloned. See
+    /// [`crate::error::PathInterner`].
+    #[cfg(feature = "intern")]
+    fn validate_with_interner(
+        &self,
+        ctx: &Self::Context,
+        interner: std::sync::Arc<crate::error::PathInterner>,
+    ) -> Result<Report, Report> {
+        let mut report = Report::with_interner(std::sync::Arc::clone(&interner));
+        self.validate_into(
+            ctx,
+            &mut || Path::interned(std::sync::Arc::clone(&interner)),
+            &mut report,
+        );
+        match report.has_errors() {
+            false => Ok(report),
+            true => Err(report),
         }
     }
 
@@ -88,20 +121,87 @@ impl<T: Validate> Unvalidated<T> {
         Self(v)
     }
 
+    /// Runs the transform pass (if any), then validates `self`, transforming
+    /// it into a `Valid<T>`. This is the only way to create an instance of
+    /// `Valid<T>`.
+    ///
+    /// When the `transform` feature is enabled, `T` is transformed in place
+    /// before validation runs, so the wrapped `Valid<T>` reflects the
+    /// normalized value rather than the original input.
+    #[cfg(feature = "transform")]
+    pub fn validate(self) -> Result<Valid<T>, Report>
+    where
+        T: crate::transform::Transform<Context = <T as Validate>::Context>,
+        <T as Validate>::Context: Default,
+    {
+        let ctx = <T as Validate>::Context::default();
+        self.validate_with(&ctx)
+    }
+
     /// Validates `self`, transforming it into a `Valid<T>`.
     /// This is the only way to create an instance of `Valid<T>`.
+    #[cfg(not(feature = "transform"))]
     pub fn validate(self) -> Result<Valid<T>, Report>
     where
         <T as Validate>::Context: Default,
     {
-        self.0.validate()?;
+        let _report = self.0.validate()?;
         Ok(Valid(self.0))
     }
 
+    /// Runs the transform pass (if any), then validates `self` against
+    /// `ctx`, transforming it into a `Valid<T>`.
+    #[cfg(feature = "transform")]
+    pub fn validate_with(self, ctx: &<T as Validate>::Context) -> Result<Valid<T>, Report>
+    where
+        T: crate::transform::Transform<Context = <T as Validate>::Context>,
+    {
+        let mut value = self.0;
+        value.transform_into(ctx, &mut Path::empty);
+        let _report = value.validate_with(ctx)?;
+        Ok(Valid(value))
+    }
+
     /// Validates `self`, transforming it into a `Valid<T>`.
     /// This is the only way to create an instance of `Valid<T>`.
+    #[cfg(not(feature = "transform"))]
     pub fn validate_with(self, ctx: &<T as Validate>::Context) -> Result<Valid<T>, Report> {
-        self.0.validate_with(ctx)?;
+        let _report = self.0.validate_with(ctx)?;
+        Ok(Valid(self.0))
+    }
+
+    /// Runs the transform pass (if any), then validates `self` against
+    /// `interner` via [`Validate::validate_with_interner`], transforming it
+    /// into a `Valid<T>`.
+    ///
+    /// When the `transform` feature is enabled, `T` is transformed in place
+    /// before validation runs, mirroring [`Unvalidated::validate_with`] so
+    /// the wrapped `Valid<T>` reflects the normalized value regardless of
+    /// which entry point built it.
+    #[cfg(all(feature = "intern", feature = "transform"))]
+    pub fn validate_with_interner(
+        self,
+        ctx: &<T as Validate>::Context,
+        interner: std::sync::Arc<crate::error::PathInterner>,
+    ) -> Result<Valid<T>, Report>
+    where
+        T: crate::transform::Transform<Context = <T as Validate>::Context>,
+    {
+        let mut value = self.0;
+        value.transform_into(ctx, &mut Path::empty);
+        let _report = value.validate_with_interner(ctx, interner)?;
+        Ok(Valid(value))
+    }
+
+    /// Like [`Unvalidated::validate_with`], but validates against `interner`
+    /// via [`Validate::validate_with_interner`].
+    #[cfg(all(feature = "intern", not(feature = "transform")))]
+    pub fn validate_with_interner(
+        self,
+        ctx: &<T as Validate>::Context,
+        interner: std::sync::Arc<crate::error::PathInterner>,
+    ) -> Result<Valid<T>, Report> {
+        let _report = self.0.validate_with_interner(ctx, interner)?;
         Ok(Valid(self.0))
     }
 }
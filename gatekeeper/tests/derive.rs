@@ -0,0 +1,112 @@
+//! Integration tests driving `#[derive(Gatekeeper)]` end to end.
+//!
+//! Unlike `rules::tests`/`intern::tests`, which exercise the plain helper
+//! functions directly, these exist to catch bugs in the derive macro's
+//! *generated* `validate_into`/`transform_into` bodies — the kind that a
+//! unit test inside `gatekeeper-derive` itself can't see, since it never
+//! actually compiles the macro's output against a real dependent crate.
+
+use gatekeeper::validate::Validate;
+use gatekeeper::Gatekeeper;
+
+#[derive(Gatekeeper)]
+struct SignupForm {
+    #[keep(email)]
+    email: String,
+    #[keep(length(min = 3, max = 20))]
+    username: String,
+    #[keep(range(min = 13))]
+    age: u8,
+}
+
+#[test]
+fn valid_struct_passes_every_rule() {
+    let form = SignupForm {
+        email: "bob@example.com".into(),
+        username: "bob".into(),
+        age: 30,
+    };
+
+    assert!(form.validate().is_ok());
+}
+
+#[test]
+fn invalid_struct_reports_one_error_per_broken_rule() {
+    let form = SignupForm {
+        email: "not-an-email".into(),
+        username: "bo".into(),
+        age: 10,
+    };
+
+    let report = form.validate().unwrap_err();
+    assert_eq!(report.iter().count(), 3);
+}
+
+// `Inner` deliberately has no `#[keep(transform(...))]` directives and no
+// `dive` field of its own — this is the regression case for the bug where
+// `Outer`'s generated `transform_into` required `Inner: Transform` but
+// `Inner`'s derive only emitted that impl when it had transform work.
+#[derive(Gatekeeper)]
+struct Inner {
+    #[keep(length(min = 1))]
+    name: String,
+}
+
+#[derive(Gatekeeper)]
+struct Outer {
+    #[keep(dive)]
+    inner: Inner,
+}
+
+#[test]
+fn dive_into_a_struct_with_no_transform_work_of_its_own_compiles_and_runs() {
+    let outer = Outer {
+        inner: Inner {
+            name: "".to_string(),
+        },
+    };
+
+    let report = outer.validate().unwrap_err();
+    assert_eq!(report.iter().count(), 1);
+}
+
+#[cfg(feature = "transform")]
+#[derive(Gatekeeper)]
+struct Trimmed {
+    #[keep(transform(trim), length(min = 1))]
+    name: String,
+}
+
+#[cfg(feature = "transform")]
+#[test]
+fn transform_runs_before_validation() {
+    use gatekeeper::validate::Unvalidated;
+
+    let valid = Unvalidated::new(Trimmed {
+        name: "  bob  ".to_string(),
+    })
+    .validate()
+    .unwrap();
+    assert_eq!(valid.name, "bob");
+}
+
+#[cfg(feature = "regex")]
+#[derive(Gatekeeper)]
+struct Sku {
+    #[keep(pattern("^[A-Z]{2}-[0-9]{4}$"))]
+    code: String,
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn pattern_rule_matches_against_the_compiled_regex() {
+    let valid = Sku {
+        code: "AB-1234".to_string(),
+    };
+    assert!(valid.validate().is_ok());
+
+    let invalid = Sku {
+        code: "not-a-sku".to_string(),
+    };
+    assert!(invalid.validate().is_err());
+}